@@ -0,0 +1,189 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+use crate::log::{
+    drain_logs, query_logs, AdvancedLogConfig, AdvancedLogConfigInput,
+    RecordFilter,
+};
+
+/// Write `message` into `*err_msg` as a newly-allocated C string. The caller
+/// owns the result and must free it with `ten_rust_free_cstring`.
+unsafe fn set_error(err_msg: *mut *mut c_char, message: &str) {
+    if err_msg.is_null() {
+        return;
+    }
+    *err_msg = CString::new(message)
+        .unwrap_or_else(|_| CString::new("invalid error message").unwrap())
+        .into_raw();
+}
+
+/// Parse `json` - the explicit `{"level", "handlers"}` form, the
+/// `{"preset", "tracked"}` shorthand, or a bare `"info,auth=debug,..."`
+/// level directive string, see [`AdvancedLogConfigInput`] - into an
+/// [`AdvancedLogConfig`] and hand ownership of it to the caller as a raw
+/// pointer.
+///
+/// # Safety
+/// `json` must be a valid, NUL-terminated C string. `err_msg` must be a
+/// valid pointer to a `*mut c_char` that this function may write to. On
+/// success the returned pointer is owned by the caller, who must eventually
+/// reclaim it with `Box::from_raw`; on failure the return value is null and
+/// `*err_msg` holds a caller-owned error string.
+#[no_mangle]
+pub unsafe extern "C" fn ten_rust_create_log_config_from_json(
+    json: *const c_char,
+    err_msg: *mut *mut c_char,
+) -> *mut AdvancedLogConfig {
+    if json.is_null() {
+        set_error(err_msg, "json pointer is null");
+        return std::ptr::null_mut();
+    }
+
+    let json_str = match CStr::from_ptr(json).to_str() {
+        Ok(s) => s,
+        Err(e) => {
+            set_error(err_msg, &format!("json is not valid UTF-8: {e}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    let input: AdvancedLogConfigInput = match serde_json::from_str(json_str) {
+        Ok(input) => input,
+        Err(e) => {
+            set_error(err_msg, &format!("failed to parse log config: {e}"));
+            return std::ptr::null_mut();
+        }
+    };
+
+    Box::into_raw(Box::new(input.resolve()))
+}
+
+/// Query the process-global in-memory log ring buffer (see
+/// [`AdvancedLogEmitter::Memory`](crate::log::AdvancedLogEmitter::Memory))
+/// and hand back a JSON array of matching records, newest-first.
+///
+/// # Safety
+/// `filter_json` must either be null (use [`RecordFilter::default`]) or a
+/// valid, NUL-terminated C string holding a JSON-encoded [`RecordFilter`].
+/// `err_msg` must be a valid pointer to a `*mut c_char` that this function
+/// may write to. On success the returned string is owned by the caller, who
+/// must free it with `ten_rust_free_cstring`; on failure the return value is
+/// null and `*err_msg` holds a caller-owned error string.
+#[no_mangle]
+pub unsafe extern "C" fn ten_rust_query_logs_json(
+    filter_json: *const c_char,
+    err_msg: *mut *mut c_char,
+) -> *mut c_char {
+    let filter = if filter_json.is_null() {
+        RecordFilter::default()
+    } else {
+        let json_str = match CStr::from_ptr(filter_json).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_error(err_msg, &format!("filter is not valid UTF-8: {e}"));
+                return std::ptr::null_mut();
+            }
+        };
+        match serde_json::from_str(json_str) {
+            Ok(filter) => filter,
+            Err(e) => {
+                set_error(
+                    err_msg,
+                    &format!("failed to parse log filter: {e}"),
+                );
+                return std::ptr::null_mut();
+            }
+        }
+    };
+
+    let records = query_logs(filter);
+    let records: Vec<&crate::log::LogRecord> =
+        records.iter().map(|record| record.as_ref()).collect();
+    let json = match serde_json::to_string(&records) {
+        Ok(json) => json,
+        Err(e) => {
+            set_error(
+                err_msg,
+                &format!("failed to serialize log records: {e}"),
+            );
+            return std::ptr::null_mut();
+        }
+    };
+
+    match CString::new(json) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(e) => {
+            set_error(
+                err_msg,
+                &format!("log record json contained a NUL byte: {e}"),
+            );
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Atomically remove and return matching records from the process-global
+/// in-memory log ring buffer (see [`drain_logs`]) as a JSON array,
+/// oldest-first, so a supervising process can forward recent internal logs
+/// elsewhere without retaining them in the buffer afterward.
+///
+/// # Safety
+/// Same contract as [`ten_rust_query_logs_json`].
+#[no_mangle]
+pub unsafe extern "C" fn ten_rust_drain_logs_json(
+    filter_json: *const c_char,
+    err_msg: *mut *mut c_char,
+) -> *mut c_char {
+    let filter = if filter_json.is_null() {
+        RecordFilter::default()
+    } else {
+        let json_str = match CStr::from_ptr(filter_json).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_error(err_msg, &format!("filter is not valid UTF-8: {e}"));
+                return std::ptr::null_mut();
+            }
+        };
+        match serde_json::from_str(json_str) {
+            Ok(filter) => filter,
+            Err(e) => {
+                set_error(
+                    err_msg,
+                    &format!("failed to parse log filter: {e}"),
+                );
+                return std::ptr::null_mut();
+            }
+        }
+    };
+
+    let records = drain_logs(filter);
+    let records: Vec<&crate::log::LogRecord> =
+        records.iter().map(|record| record.as_ref()).collect();
+    let json = match serde_json::to_string(&records) {
+        Ok(json) => json,
+        Err(e) => {
+            set_error(
+                err_msg,
+                &format!("failed to serialize log records: {e}"),
+            );
+            return std::ptr::null_mut();
+        }
+    };
+
+    match CString::new(json) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(e) => {
+            set_error(
+                err_msg,
+                &format!("log record json contained a NUL byte: {e}"),
+            );
+            std::ptr::null_mut()
+        }
+    }
+}
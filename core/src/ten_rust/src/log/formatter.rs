@@ -0,0 +1,507 @@
+//
+// Copyright © 2025 Agora
+// This file is part of TEN Framework, an open source project.
+// Licensed under the Apache License, Version 2.0, with certain conditions.
+// Refer to the "LICENSE" file in the root directory for more information.
+//
+use std::fmt;
+use std::time::Instant;
+
+use once_cell::sync::Lazy;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::{format, FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::log::extract_event_fields;
+
+static PROCESS_START: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// How `PlainFormatter` renders an event's timestamp.
+enum TimeFormat {
+    /// The built-in default: RFC 3339 with second precision.
+    Rfc3339,
+    /// A `chrono` strftime-style string, e.g. `"%Y-%m-%d %H:%M:%S"`.
+    Strftime(String),
+    /// Seconds elapsed since the process started, e.g. `"12.345"`.
+    Uptime,
+}
+
+impl TimeFormat {
+    fn parse(time_format: Option<&str>) -> Self {
+        match time_format {
+            None | Some("rfc3339") => TimeFormat::Rfc3339,
+            Some("uptime") => TimeFormat::Uptime,
+            Some(strftime) => TimeFormat::Strftime(strftime.to_string()),
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            TimeFormat::Rfc3339 => chrono::Utc::now().to_rfc3339_opts(
+                chrono::SecondsFormat::Secs,
+                true,
+            ),
+            TimeFormat::Strftime(fmt) => {
+                chrono::Utc::now().format(fmt).to_string()
+            }
+            TimeFormat::Uptime => {
+                format!("{:.3}", PROCESS_START.elapsed().as_secs_f64())
+            }
+        }
+    }
+}
+
+/// A single token of a parsed [`AdvancedLogFormatter::pattern`].
+///
+/// Parsing the template once at construction (rather than re-scanning the
+/// string for every event) keeps the hot path a cheap walk over a `Vec`.
+enum Token {
+    Literal(String),
+    Time,
+    Level,
+    Target,
+    Pid,
+    Tid,
+    File,
+    Line,
+    Func,
+    Msg,
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            literal.push(c);
+            continue;
+        }
+
+        let mut placeholder = String::new();
+        let mut closed = false;
+        for next in chars.by_ref() {
+            if next == '}' {
+                closed = true;
+                break;
+            }
+            placeholder.push(next);
+        }
+
+        if !closed {
+            // Unterminated `{...}`: treat the rest as a literal instead of
+            // silently dropping it.
+            literal.push('{');
+            literal.push_str(&placeholder);
+            continue;
+        }
+
+        let token = match placeholder.as_str() {
+            "time" => Some(Token::Time),
+            "level" => Some(Token::Level),
+            "target" => Some(Token::Target),
+            "pid" => Some(Token::Pid),
+            "tid" => Some(Token::Tid),
+            "file" => Some(Token::File),
+            "line" => Some(Token::Line),
+            "func" => Some(Token::Func),
+            "msg" => Some(Token::Msg),
+            _ => None,
+        };
+
+        match token {
+            Some(token) => {
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                }
+                tokens.push(token);
+            }
+            None => {
+                // Unknown placeholder: keep it verbatim so a typo is visible
+                // in the output rather than silently swallowed.
+                literal.push('{');
+                literal.push_str(&placeholder);
+                literal.push('}');
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+
+    tokens
+}
+
+/// Plain-text event formatter used by console and file emitters.
+///
+/// By default it renders a fixed `TIME LEVEL [pid:tid] target (file:line
+/// func) - msg` layout. Passing a `pattern` at construction switches to a
+/// user-defined field order instead.
+pub struct PlainFormatter {
+    ansi: bool,
+    tokens: Option<Vec<Token>>,
+    time_format: TimeFormat,
+}
+
+impl PlainFormatter {
+    pub fn new(
+        ansi: bool,
+        pattern: Option<&str>,
+        time_format: Option<&str>,
+    ) -> Self {
+        Self {
+            ansi,
+            tokens: pattern.map(parse_pattern),
+            time_format: TimeFormat::parse(time_format),
+        }
+    }
+
+    fn level_color(&self, level: &tracing::Level) -> (&'static str, &'static str) {
+        if !self.ansi {
+            return ("", "");
+        }
+        match *level {
+            tracing::Level::TRACE => ("\x1b[90m", "\x1b[0m"),
+            tracing::Level::DEBUG => ("\x1b[34m", "\x1b[0m"),
+            tracing::Level::INFO => ("\x1b[32m", "\x1b[0m"),
+            tracing::Level::WARN => ("\x1b[33m", "\x1b[0m"),
+            tracing::Level::ERROR => ("\x1b[31m", "\x1b[0m"),
+        }
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for PlainFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let fields = extract_event_fields(event);
+        let (color_start, color_end) = self.level_color(metadata.level());
+
+        match &self.tokens {
+            Some(tokens) => {
+                for token in tokens {
+                    match token {
+                        Token::Literal(text) => write!(writer, "{text}")?,
+                        Token::Time => {
+                            write!(writer, "{}", self.time_format.render())?
+                        }
+                        Token::Level => write!(
+                            writer,
+                            "{color_start}{}{color_end}",
+                            metadata.level()
+                        )?,
+                        Token::Target => write!(
+                            writer,
+                            "{}",
+                            fields.category_or_target(metadata)
+                        )?,
+                        Token::Pid => write!(writer, "{}", fields.pid)?,
+                        Token::Tid => write!(writer, "{}", fields.tid)?,
+                        Token::File => write!(writer, "{}", fields.file_name)?,
+                        Token::Line => write!(writer, "{}", fields.line_no)?,
+                        Token::Func => write!(writer, "{}", fields.func_name)?,
+                        Token::Msg => write!(writer, "{}", fields.message)?,
+                    }
+                }
+                writeln!(writer)
+            }
+            None => {
+                writeln!(
+                    writer,
+                    "{} {color_start}{}{color_end} [{}:{}] {} ({}:{} {}) - {}",
+                    self.time_format.render(),
+                    metadata.level(),
+                    fields.pid,
+                    fields.tid,
+                    fields.category_or_target(metadata),
+                    fields.file_name,
+                    fields.line_no,
+                    fields.func_name,
+                    fields.message,
+                )
+            }
+        }
+    }
+}
+
+/// Names used for each field when [`JsonFormatter`] serializes an event.
+/// Lets callers match an existing ingestion pipeline's expected schema.
+#[derive(Debug, Clone)]
+pub struct JsonFieldNames {
+    pub time: &'static str,
+    pub level: &'static str,
+    pub target: &'static str,
+    pub pid: &'static str,
+    pub tid: &'static str,
+    pub file: &'static str,
+    pub line: &'static str,
+    pub func: &'static str,
+    pub msg: &'static str,
+}
+
+impl Default for JsonFieldNames {
+    fn default() -> Self {
+        Self {
+            time: "time",
+            level: "level",
+            target: "target",
+            pid: "pid",
+            tid: "tid",
+            file: "file",
+            line: "line",
+            func: "func",
+            msg: "msg",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonConfig {
+    pub ansi: bool,
+    pub pretty: bool,
+    pub field_names: JsonFieldNames,
+}
+
+/// JSON event formatter used by console and file emitters.
+pub struct JsonFormatter {
+    config: JsonConfig,
+}
+
+impl JsonFormatter {
+    pub fn new(config: JsonConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for JsonFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let fields = extract_event_fields(event);
+        let names = &self.config.field_names;
+
+        let entries = [
+            (
+                names.time,
+                serde_json::Value::String(chrono::Utc::now().to_rfc3339()),
+            ),
+            (
+                names.level,
+                serde_json::Value::String(metadata.level().to_string()),
+            ),
+            (
+                names.target,
+                serde_json::Value::String(
+                    fields.category_or_target(metadata).to_string(),
+                ),
+            ),
+            (names.pid, serde_json::Value::from(fields.pid)),
+            (names.tid, serde_json::Value::from(fields.tid)),
+            (names.file, serde_json::Value::String(fields.file_name)),
+            (names.line, serde_json::Value::from(fields.line_no)),
+            (names.func, serde_json::Value::String(fields.func_name)),
+            (names.msg, serde_json::Value::String(fields.message)),
+        ];
+
+        let object: serde_json::Map<String, serde_json::Value> = entries
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        let value = serde_json::Value::Object(object);
+
+        let rendered = if self.config.pretty {
+            serde_json::to_string_pretty(&value)
+        } else {
+            serde_json::to_string(&value)
+        }
+        .map_err(|_| fmt::Error)?;
+
+        writeln!(writer, "{rendered}")
+    }
+}
+
+/// RFC 3164 (BSD) vs RFC 5424 syslog message framing, chosen by
+/// `SyslogEmitterConfig::format`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyslogFraming {
+    Rfc3164,
+    Rfc5424,
+}
+
+/// Frames events as syslog messages (RFC 3164 or RFC 5424) for
+/// `AdvancedLogEmitter::Syslog`. Unlike `PlainFormatter`/`JsonFormatter`,
+/// each line carries its own PRI (facility + severity) and header fields
+/// rather than a flat list of named fields.
+pub struct SyslogFormatter {
+    pri_base: u8,
+    app_name: String,
+    framing: SyslogFraming,
+}
+
+impl SyslogFormatter {
+    /// `pri_base` is the facility code already multiplied by 8 (see
+    /// `SyslogFacility::code`); each event's severity is added to it to
+    /// form the final PRI.
+    pub fn new(pri_base: u8, app_name: String, framing: SyslogFraming) -> Self {
+        Self { pri_base, app_name, framing }
+    }
+
+    fn severity(level: &tracing::Level) -> u8 {
+        match *level {
+            tracing::Level::ERROR => 3,
+            tracing::Level::WARN => 4,
+            tracing::Level::INFO => 6,
+            tracing::Level::DEBUG | tracing::Level::TRACE => 7,
+        }
+    }
+
+    fn hostname() -> String {
+        // No hostname-resolution crate is in use elsewhere in this codebase;
+        // fall back to the RFC 5424 NILVALUE rather than add one just for
+        // this. Most syslog relays fill in the hostname themselves anyway.
+        std::env::var("HOSTNAME").unwrap_or_else(|_| "-".to_string())
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for SyslogFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let fields = extract_event_fields(event);
+        let pri = self.pri_base + Self::severity(metadata.level());
+        let category = fields.category_or_target(metadata);
+        let hostname = Self::hostname();
+
+        match self.framing {
+            SyslogFraming::Rfc3164 => {
+                // `%b %e` (space-padded day) is the traditional BSD syslog
+                // timestamp; RFC 3164 has no room for a category, so fold it
+                // into the message itself.
+                let timestamp =
+                    chrono::Utc::now().format("%b %e %H:%M:%S");
+                writeln!(
+                    writer,
+                    "<{pri}>{timestamp} {hostname} {}[{}:{}]: {category}: {}",
+                    self.app_name, fields.pid, fields.tid, fields.message,
+                )
+            }
+            SyslogFraming::Rfc5424 => {
+                let timestamp = chrono::Utc::now()
+                    .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+                let msgid = if category.is_empty() { "-" } else { category };
+                writeln!(
+                    writer,
+                    "<{pri}>1 {timestamp} {hostname} {} {}:{} {msgid} - {}",
+                    self.app_name, fields.pid, fields.tid, fields.message,
+                )
+            }
+        }
+    }
+}
+
+/// Frames each event as a single OTLP JSON `LogRecord` object (not a whole
+/// `ExportLogsServiceRequest`) for `AdvancedLogEmitter::Otlp`. Each
+/// formatted record is one `write()` call to `OtlpBatchSender`, which queues
+/// it for `OtlpBatchWorker` to wrap N of them into one
+/// `ExportLogsServiceRequest` per flush instead of exporting one request per
+/// record; see that worker for the batching and the `resourceLogs`/
+/// `scopeLogs` envelope this formatter leaves off.
+pub struct OtlpFormatter;
+
+impl OtlpFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn severity_number(level: &tracing::Level) -> u8 {
+        match *level {
+            tracing::Level::TRACE => 1,
+            tracing::Level::DEBUG => 5,
+            tracing::Level::INFO => 9,
+            tracing::Level::WARN => 13,
+            tracing::Level::ERROR => 17,
+        }
+    }
+}
+
+impl Default for OtlpFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for OtlpFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let fields = extract_event_fields(event);
+        let category = fields.category_or_target(metadata).to_string();
+        let time_unix_nano = chrono::Utc::now()
+            .timestamp_nanos_opt()
+            .unwrap_or_default();
+
+        let string_attr = |key: &str, value: String| {
+            serde_json::json!({
+                "key": key,
+                "value": { "stringValue": value },
+            })
+        };
+        let int_attr = |key: &str, value: i64| {
+            serde_json::json!({
+                "key": key,
+                "value": { "intValue": value.to_string() },
+            })
+        };
+
+        let log_record = serde_json::json!({
+            "timeUnixNano": time_unix_nano.to_string(),
+            "severityNumber": Self::severity_number(metadata.level()),
+            "severityText": metadata.level().to_string(),
+            "body": { "stringValue": fields.message },
+            "attributes": [
+                string_attr("category", category),
+                int_attr("pid", fields.pid),
+                int_attr("tid", fields.tid),
+                string_attr("file", fields.file_name),
+                int_attr("line", fields.line_no as i64),
+                string_attr("func", fields.func_name),
+            ],
+        });
+
+        write!(writer, "{log_record}")
+    }
+}
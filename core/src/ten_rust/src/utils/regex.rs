@@ -5,20 +5,373 @@
 // Refer to the "LICENSE" file in the root directory for more information.
 //
 
-use anyhow::Result;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
 
 const ALPHANUMERIC_CHARACTERS_PATTERN: &str = r"^[A-Za-z_][A-Za-z0-9_]*$";
+const UNICODE_ALPHANUMERIC_CHARACTERS_PATTERN: &str =
+    r"^[\p{L}_][\p{L}\p{N}_]*$";
+
+/// Patterns in these APIs come from config files and other user-supplied
+/// input, not just trusted source, so every compile here goes through the
+/// same two guards: a cap on the source text itself (a pattern no one would
+/// type by hand is more likely a mistake or an attack than a real rule),
+/// and a cap on the compiled program's size (the `regex` crate matches in
+/// linear time, but an adversarial pattern can still blow up the size of
+/// the compiled automaton well before any matching happens).
+const MAX_PATTERN_LENGTH: usize = 1024;
+const MAX_COMPILED_SIZE_BYTES: usize = 1 << 20;
+
+/// Upper bound on how many distinct compiled patterns [`REGEX_CACHE`] (and
+/// its siblings) hold onto at once. Patterns here come from config and
+/// other user-supplied input; without a cap, a source that keeps
+/// generating new distinct patterns (adversarially, or just a config that
+/// interpolates values into a pattern) would grow the cache for the life
+/// of the process.
+const MAX_CACHED_PATTERNS: usize = 512;
+
+/// A fixed-capacity cache that evicts the least-recently-used entry once
+/// full, so callers that compile a bounded set of config-driven patterns
+/// get full reuse while an unbounded or adversarial set of distinct
+/// patterns can't grow the cache forever.
+struct BoundedLruCache<K, V> {
+    capacity: usize,
+    entries: std::collections::HashMap<K, V>,
+    // Least-recently-used at the front, most-recently-used at the back.
+    order: VecDeque<K>,
+}
+
+impl<K: Eq + Hash + Clone, V> BoundedLruCache<K, V> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: std::collections::HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        if self.entries.contains_key(key) {
+            self.touch(key);
+        }
+        self.entries.get(key)
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let key = self.order.remove(pos).unwrap();
+            self.order.push_back(key);
+        }
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+        } else {
+            if self.entries.len() >= self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.order.push_back(key.clone());
+        }
+        self.entries.insert(key, value);
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// Backtracking step budget for [`fancy_regex_match`]. Unlike the `regex`
+/// crate, `fancy-regex` supports lookaround/backreferences by backtracking,
+/// which makes it vulnerable to classic catastrophic-backtracking ReDoS;
+/// this bounds the damage a single adversarial match attempt can do.
+#[cfg(feature = "fancy-regex")]
+const MAX_BACKTRACK_STEPS: usize = 1_000_000;
+
+fn check_pattern_length(pattern: &str) -> Result<()> {
+    if pattern.len() > MAX_PATTERN_LENGTH {
+        return Err(anyhow!(
+            "regex pattern is {} bytes, exceeding the {} byte limit",
+            pattern.len(),
+            MAX_PATTERN_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+/// Compiled patterns handed out by [`regex_match`], keyed by pattern text,
+/// bounded to [`MAX_CACHED_PATTERNS`] entries. Callers such as the log
+/// query path run `regex_match` with the same pattern once per record they
+/// scan, so recompiling the same pattern on every call would turn a hot
+/// filtering path into a regex-compilation benchmark.
+static REGEX_CACHE: Lazy<Mutex<BoundedLruCache<String, Regex>>> =
+    Lazy::new(|| Mutex::new(BoundedLruCache::with_capacity(MAX_CACHED_PATTERNS)));
+
+/// Current number of patterns held in [`REGEX_CACHE`], so callers (tests in
+/// particular) can confirm the cache actually stays bounded at
+/// [`MAX_CACHED_PATTERNS`] rather than only checking that matching is
+/// correct.
+pub fn regex_cache_len() -> usize {
+    REGEX_CACHE.lock().unwrap().len()
+}
 
 pub fn regex_match(pattern: &str, text: &str) -> Result<bool> {
-    let re = Regex::new(pattern)?;
-    Ok(re.is_match(text))
+    let mut cache = REGEX_CACHE.lock().unwrap();
+    if let Some(re) = cache.get(&pattern.to_string()) {
+        return Ok(re.is_match(text));
+    }
+
+    check_pattern_length(pattern)?;
+    let re = RegexBuilder::new(pattern)
+        .size_limit(MAX_COMPILED_SIZE_BYTES)
+        .build()?;
+    let is_match = re.is_match(text);
+    cache.insert(pattern.to_string(), re);
+    Ok(is_match)
 }
 
-pub fn is_alphanumeric_characters(text: &str) -> bool {
+/// Compiled sets handed out by [`regex_set_match`]/[`regex_set_any`], keyed
+/// by the pattern list itself (not a joined string: `["a\nb", "c"]` and
+/// `["a", "b\nc"]` must not collide on the same cache slot), bounded to
+/// [`MAX_CACHED_PATTERNS`] for the same reason [`REGEX_CACHE`] is.
+static REGEX_SET_CACHE: Lazy<Mutex<BoundedLruCache<Vec<String>, RegexSet>>> =
+    Lazy::new(|| {
+        Mutex::new(BoundedLruCache::with_capacity(MAX_CACHED_PATTERNS))
+    });
+
+/// Current number of pattern sets held in [`REGEX_SET_CACHE`], mirroring
+/// [`regex_cache_len`].
+pub fn regex_set_cache_len() -> usize {
+    REGEX_SET_CACHE.lock().unwrap().len()
+}
+
+fn get_or_build_regex_set(patterns: &[&str]) -> Result<RegexSet> {
+    let key: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+
+    let mut cache = REGEX_SET_CACHE.lock().unwrap();
+    if let Some(set) = cache.get(&key) {
+        return Ok(set.clone());
+    }
+
+    for pattern in patterns {
+        check_pattern_length(pattern)?;
+    }
+    let set = RegexSetBuilder::new(patterns)
+        .size_limit(MAX_COMPILED_SIZE_BYTES)
+        .build()?;
+    cache.insert(key, set.clone());
+    Ok(set)
+}
+
+/// Check `text` against many patterns at once and return the indices (into
+/// `patterns`, in ascending order) of the ones that match, using a single
+/// `RegexSet` pass rather than looping over [`regex_match`] once per
+/// pattern. Prefer this over the single-pattern form when validating a
+/// record against a whole rule set, since `RegexSet` evaluates all patterns
+/// together in one scan of `text` instead of one scan per pattern.
+pub fn regex_set_match(patterns: &[&str], text: &str) -> Result<Vec<usize>> {
+    let set = get_or_build_regex_set(patterns)?;
+    Ok(set.matches(text).into_iter().collect())
+}
+
+/// Like [`regex_set_match`], but for callers that only need to know
+/// whether *any* pattern matched, not which ones: `RegexSet::is_match`
+/// can short-circuit on the first match instead of collecting every index.
+pub fn regex_set_any(patterns: &[&str], text: &str) -> Result<bool> {
+    let set = get_or_build_regex_set(patterns)?;
+    Ok(set.is_match(text))
+}
+
+/// Compiled patterns handed out by [`fancy_regex_match`], mirroring
+/// [`REGEX_CACHE`] but keyed separately since a `fancy_regex::Regex` isn't
+/// interchangeable with a `regex::Regex`.
+#[cfg(feature = "fancy-regex")]
+static FANCY_REGEX_CACHE: Lazy<
+    Mutex<BoundedLruCache<String, fancy_regex::Regex>>,
+> = Lazy::new(|| {
+    Mutex::new(BoundedLruCache::with_capacity(MAX_CACHED_PATTERNS))
+});
+
+/// Like [`regex_match`], but backed by the `fancy-regex` engine, which
+/// supports lookaround and backreferences that the `regex` crate rejects at
+/// compile time (it only ever matches in linear time, so it can't support
+/// them). Only reach for this when a pattern actually needs one of those
+/// constructs: `fancy-regex` can be exponential on adversarial input, so
+/// [`regex_match`] stays the default for everything else.
+#[cfg(feature = "fancy-regex")]
+pub fn fancy_regex_match(pattern: &str, text: &str) -> Result<bool> {
+    let mut cache = FANCY_REGEX_CACHE.lock().unwrap();
+    if let Some(re) = cache.get(&pattern.to_string()) {
+        return Ok(re.is_match(text)?);
+    }
+
+    check_pattern_length(pattern)?;
+    let re = fancy_regex::RegexBuilder::new(pattern)
+        .backtrack_limit(MAX_BACKTRACK_STEPS)
+        .build()?;
+    let is_match = re.is_match(text)?;
+    cache.insert(pattern.to_string(), re);
+    Ok(is_match)
+}
+
+/// Size/complexity limits passed to [`compile_regex_limited`], in place of
+/// the crate-wide [`MAX_PATTERN_LENGTH`]/[`MAX_COMPILED_SIZE_BYTES`]
+/// defaults that [`regex_match`] uses.
+#[derive(Debug, Clone, Copy)]
+pub struct RegexLimits {
+    /// Source pattern text longer than this is rejected before compiling.
+    pub max_pattern_length: usize,
+    /// Upper bound on the compiled program's size, in bytes.
+    pub max_compiled_size_bytes: usize,
+    /// Upper bound on the size of the lazily-built DFA cache, in bytes.
+    pub max_dfa_size_bytes: usize,
+}
+
+impl Default for RegexLimits {
+    fn default() -> Self {
+        Self {
+            max_pattern_length: MAX_PATTERN_LENGTH,
+            max_compiled_size_bytes: MAX_COMPILED_SIZE_BYTES,
+            max_dfa_size_bytes: MAX_COMPILED_SIZE_BYTES,
+        }
+    }
+}
+
+/// Why [`compile_regex_limited`] rejected a pattern, so a caller taking
+/// untrusted rules can reject an oversized pattern differently from a
+/// plain syntax error (e.g. log and skip the rule either way, but only
+/// alert on the former).
+#[derive(Debug)]
+pub enum RegexLimitError {
+    /// The pattern's source text or compiled program exceeded `limits`.
+    TooLarge(String),
+    /// The pattern failed to compile for reasons unrelated to size.
+    Invalid(regex::Error),
+}
+
+impl std::fmt::Display for RegexLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegexLimitError::TooLarge(message) => write!(f, "{message}"),
+            RegexLimitError::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for RegexLimitError {}
+
+/// Compile `pattern` under `limits`, distinguishing a too-large pattern
+/// from an invalid one via [`RegexLimitError`]. Does not share
+/// [`REGEX_CACHE`], since different callers may pass different limits for
+/// the same pattern text; callers that compile once and match repeatedly
+/// (e.g. a handler's filter, built once per config reload) should hold
+/// onto the returned [`Regex`] themselves.
+pub fn compile_regex_limited(
+    pattern: &str,
+    limits: &RegexLimits,
+) -> std::result::Result<Regex, RegexLimitError> {
+    if pattern.len() > limits.max_pattern_length {
+        return Err(RegexLimitError::TooLarge(format!(
+            "regex pattern is {} bytes, exceeding the {} byte limit",
+            pattern.len(),
+            limits.max_pattern_length
+        )));
+    }
+
+    RegexBuilder::new(pattern)
+        .size_limit(limits.max_compiled_size_bytes)
+        .dfa_size_limit(limits.max_dfa_size_bytes)
+        .build()
+        .map_err(|e| match e {
+            regex::Error::CompiledTooBig(_) => {
+                RegexLimitError::TooLarge(e.to_string())
+            }
+            other => RegexLimitError::Invalid(other),
+        })
+}
+
+/// Which character classes [`is_valid_identifier`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierMode {
+    /// Only `A-Za-z0-9_`, matching the identifiers `EnvFilter` directives
+    /// and module paths are restricted to.
+    Ascii,
+    /// Any Unicode letter or number (`\p{L}`/`\p{N}`) plus `_`, for
+    /// category names that aren't tied to a Rust module path.
+    Unicode,
+}
+
+/// Whether `text` is a valid identifier: a leading letter or underscore
+/// followed by letters, digits, or underscores, per `mode`'s character
+/// classes (see [`IdentifierMode`]).
+pub fn is_valid_identifier(text: &str, mode: IdentifierMode) -> bool {
     static ALPHANUMERIC_CHARACTERS_REGEX: Lazy<Regex> =
         Lazy::new(|| Regex::new(ALPHANUMERIC_CHARACTERS_PATTERN).unwrap());
+    static UNICODE_ALPHANUMERIC_CHARACTERS_REGEX: Lazy<Regex> = Lazy::new(
+        || Regex::new(UNICODE_ALPHANUMERIC_CHARACTERS_PATTERN).unwrap(),
+    );
+
+    match mode {
+        IdentifierMode::Ascii => ALPHANUMERIC_CHARACTERS_REGEX.is_match(text),
+        IdentifierMode::Unicode => {
+            UNICODE_ALPHANUMERIC_CHARACTERS_REGEX.is_match(text)
+        }
+    }
+}
+
+/// Whether `text` is a valid ASCII identifier: a leading ASCII letter or
+/// underscore followed by ASCII letters, digits, or underscores. Kept as
+/// the original, ASCII-only entry point for callers that predate
+/// [`is_valid_identifier`]; equivalent to
+/// `is_valid_identifier(text, IdentifierMode::Ascii)`.
+pub fn is_alphanumeric_characters(text: &str) -> bool {
+    is_valid_identifier(text, IdentifierMode::Ascii)
+}
+
+/// Collect `pattern`'s named capture groups (e.g. `(?P<id>\d+)`) from the
+/// first match in `text`, keyed by group name. Returns `None` if `pattern`
+/// doesn't match `text` at all; a group that's part of a non-matching
+/// alternative is simply absent from the returned map rather than mapped to
+/// an empty string. Shares [`REGEX_CACHE`] with [`regex_match`], since both
+/// compile the same kind of pattern.
+pub fn regex_captures(
+    pattern: &str,
+    text: &str,
+) -> Result<Option<std::collections::HashMap<String, String>>> {
+    fn extract(
+        re: &Regex,
+        text: &str,
+    ) -> Option<std::collections::HashMap<String, String>> {
+        let captures = re.captures(text)?;
+        Some(
+            re.capture_names()
+                .flatten()
+                .filter_map(|name| {
+                    captures
+                        .name(name)
+                        .map(|m| (name.to_string(), m.as_str().to_string()))
+                })
+                .collect(),
+        )
+    }
+
+    let mut cache = REGEX_CACHE.lock().unwrap();
+    if let Some(re) = cache.get(&pattern.to_string()) {
+        return Ok(extract(re, text));
+    }
 
-    ALPHANUMERIC_CHARACTERS_REGEX.is_match(text)
+    check_pattern_length(pattern)?;
+    let re = RegexBuilder::new(pattern)
+        .size_limit(MAX_COMPILED_SIZE_BYTES)
+        .build()?;
+    let result = extract(&re, text);
+    cache.insert(pattern.to_string(), re);
+    Ok(result)
 }
@@ -8,16 +8,26 @@
 mod tests {
     use std::fs;
     use std::os::raw::c_char;
+    use std::path::PathBuf;
     use std::thread;
     use std::time::Duration;
     use ten_rust::{
         bindings::ten_rust_free_cstring,
         log::{
-            bindings::ten_rust_create_log_config_from_json, ten_configure_log,
-            ten_log, AdvancedLogConfig, AdvancedLogEmitter,
-            AdvancedLogFormatter, AdvancedLogHandler, AdvancedLogLevel,
-            AdvancedLogMatcher, ConsoleEmitterConfig, FileEmitterConfig,
-            FormatterType, LogLevel, StreamType,
+            bindings::{
+                ten_rust_create_log_config_from_json, ten_rust_drain_logs_json,
+                ten_rust_query_logs_json,
+            },
+            query_logs, ten_configure_log, ten_log, AdvancedLogConfig,
+            AdvancedLogConfigInput, AdvancedLogEmitter, AdvancedLogFormatter,
+            AdvancedLogHandler, AdvancedLogLevel, AdvancedLogMatcher,
+            ConsoleEmitterConfig, FileEmitterConfig, FileRotation,
+            FormatterType, LogLevel, LogPreset, MemoryEmitterConfig,
+            OtlpEmitterConfig, OtlpProtocol, OverflowPolicy, PresetLogConfig,
+            RecordFilter,
+            RollingFileEmitterConfig, RollingFileRotation, SplitStreamConfig,
+            StreamType, SyslogEmitterConfig, SyslogFacility,
+            SyslogMessageFormat, SyslogTransport,
         },
     };
     use tracing::{debug, info, trace};
@@ -55,6 +65,225 @@ mod tests {
         assert_eq!(log_config.level, Some(AdvancedLogLevel::Debug));
     }
 
+    #[test]
+    fn test_create_log_config_from_json_preset() {
+        let log_config_json = r#"{
+            "preset": "detailed",
+            "tracked": ["extension_a", ".*_asr_extension"]
+        }"#;
+
+        let mut err_msg: *mut c_char = std::ptr::null_mut();
+
+        let log_config_ptr = unsafe {
+            let c_string = std::ffi::CString::new(log_config_json).unwrap();
+            ten_rust_create_log_config_from_json(
+                c_string.as_ptr(),
+                &mut err_msg,
+            )
+        };
+
+        assert!(err_msg.is_null());
+        assert!(!log_config_ptr.is_null());
+
+        let log_config =
+            unsafe { Box::from_raw(log_config_ptr as *mut AdvancedLogConfig) };
+
+        assert_eq!(log_config.level, Some(AdvancedLogLevel::Debug));
+        let handlers = log_config
+            .handlers
+            .expect("preset should synthesize handlers");
+        assert_eq!(handlers.len(), 1);
+        assert_eq!(handlers[0].matchers.len(), 3);
+        assert_eq!(handlers[0].matchers[0].level, AdvancedLogLevel::Debug);
+        assert_eq!(
+            handlers[0].matchers[0].category.as_deref(),
+            Some("extension_a")
+        );
+        assert_eq!(handlers[0].matchers[2].level, AdvancedLogLevel::Info);
+        assert_eq!(handlers[0].matchers[2].category, None);
+    }
+
+    #[test]
+    fn test_ten_log_preset_tracked_category_routing() {
+        // `resolve_preset` synthesizes plain-category matchers from
+        // `tracked`; verify they actually route a `ten_log` call by its
+        // runtime category rather than only affecting the global level.
+        let log_file =
+            tempfile::NamedTempFile::new().expect("Failed to create temp file");
+
+        let mut config = AdvancedLogConfigInput::Preset(PresetLogConfig {
+            preset: LogPreset::Detailed,
+            tracked: vec!["extension_a".to_string()],
+        })
+        .resolve();
+        config.handlers.as_mut().unwrap()[0].emitter =
+            AdvancedLogEmitter::File(FileEmitterConfig {
+                path: log_file.path().to_str().unwrap().to_string(),
+                rotation: None,
+            });
+
+        let _guard = ten_configure_log(&config);
+
+        // Tracked category: detailed preset allows debug.
+        ten_log(
+            &config,
+            "extension_a",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            1,
+            "tracked debug detail",
+        );
+        // Untracked category: background level is info, debug is dropped.
+        ten_log(
+            &config,
+            "extension_b",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            2,
+            "background debug detail",
+        );
+        ten_log(
+            &config,
+            "extension_b",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            3,
+            "background info detail",
+        );
+
+        thread::sleep(Duration::from_millis(200));
+
+        let log_content = fs::read_to_string(log_file.path())
+            .expect("Failed to read log file");
+
+        assert!(log_content.contains("tracked debug detail"));
+        assert!(!log_content.contains("background debug detail"));
+        assert!(log_content.contains("background info detail"));
+    }
+
+    #[test]
+    fn test_create_log_config_from_json_level_directives() {
+        let log_config_json = r#""info,auth=debug,database=trace,network=off""#;
+
+        let mut err_msg: *mut c_char = std::ptr::null_mut();
+
+        let log_config_ptr = unsafe {
+            let c_string = std::ffi::CString::new(log_config_json).unwrap();
+            ten_rust_create_log_config_from_json(
+                c_string.as_ptr(),
+                &mut err_msg,
+            )
+        };
+
+        assert!(err_msg.is_null());
+        assert!(!log_config_ptr.is_null());
+
+        let log_config =
+            unsafe { Box::from_raw(log_config_ptr as *mut AdvancedLogConfig) };
+
+        assert_eq!(log_config.level, Some(AdvancedLogLevel::Info));
+        let handlers = log_config
+            .handlers
+            .expect("directive string should synthesize handlers");
+        assert_eq!(handlers.len(), 1);
+
+        let matchers = &handlers[0].matchers;
+        assert_eq!(matchers.len(), 4);
+        assert_eq!(matchers[0].category.as_deref(), Some("auth"));
+        assert_eq!(matchers[0].level, AdvancedLogLevel::Debug);
+        assert_eq!(matchers[1].category.as_deref(), Some("database"));
+        assert_eq!(matchers[1].level, AdvancedLogLevel::Trace);
+        assert_eq!(matchers[2].category.as_deref(), Some("network"));
+        assert_eq!(matchers[2].level, AdvancedLogLevel::Off);
+        assert_eq!(matchers[3].category, None);
+        assert_eq!(matchers[3].level, AdvancedLogLevel::Info);
+    }
+
+    #[test]
+    fn test_ten_log_level_directives_category_routing() {
+        // `resolve_level_directives` synthesizes plain-category matchers
+        // from a directive string; verify "auth=debug,network=off" actually
+        // routes a `ten_log` call by its runtime category.
+        let log_file =
+            tempfile::NamedTempFile::new().expect("Failed to create temp file");
+
+        let mut config = AdvancedLogConfigInput::Directives(
+            "info,auth=debug,network=off".to_string(),
+        )
+        .resolve();
+        config.handlers.as_mut().unwrap()[0].emitter =
+            AdvancedLogEmitter::File(FileEmitterConfig {
+                path: log_file.path().to_str().unwrap().to_string(),
+                rotation: None,
+            });
+
+        let _guard = ten_configure_log(&config);
+
+        ten_log(
+            &config,
+            "auth",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            1,
+            "auth debug detail",
+        );
+        ten_log(
+            &config,
+            "network",
+            1,
+            1,
+            LogLevel::Error,
+            "f",
+            "file.rs",
+            2,
+            "network suppressed error",
+        );
+        ten_log(
+            &config,
+            "unrelated",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            3,
+            "default info detail",
+        );
+        ten_log(
+            &config,
+            "unrelated",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            4,
+            "default debug detail",
+        );
+
+        thread::sleep(Duration::from_millis(200));
+
+        let log_content = fs::read_to_string(log_file.path())
+            .expect("Failed to read log file");
+
+        assert!(log_content.contains("auth debug detail"));
+        assert!(!log_content.contains("network suppressed error"));
+        assert!(log_content.contains("default info detail"));
+        assert!(!log_content.contains("default debug detail"));
+    }
+
     #[test]
     fn test_log_level_info() {
         let temp_file = tempfile::NamedTempFile::new().unwrap();
@@ -66,18 +295,23 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Info,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::File(FileEmitterConfig {
                     path: path.to_string(),
+                    rotation: None,
                 }),
             }]),
         };
 
-        ten_configure_log(&config);
+        let _guard = ten_configure_log(&config);
 
         ten_log(
             &config,
@@ -163,10 +397,14 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Trace, // Allow all log levels
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(true),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
                     stream: StreamType::Stdout,
@@ -174,7 +412,7 @@ mod tests {
             }]),
         };
 
-        ten_configure_log(&plain_colored_config);
+        let _guard = ten_configure_log(&plain_colored_config);
         // Test different log levels to see different colors
         ten_log(
             &plain_colored_config,
@@ -235,10 +473,14 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Info,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
                     stream: StreamType::Stdout,
@@ -247,7 +489,7 @@ mod tests {
         };
 
         println!("Testing Plain formatter without colors:");
-        ten_configure_log(&plain_no_color_config);
+        let _guard = ten_configure_log(&plain_no_color_config);
         ten_log(
             &plain_no_color_config,
             "test_category",
@@ -271,10 +513,14 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Info,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Json,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
                     stream: StreamType::Stdout,
@@ -283,7 +529,7 @@ mod tests {
         };
 
         println!("Testing JSON formatter:");
-        ten_configure_log(&json_config);
+        let _guard = ten_configure_log(&json_config);
         ten_log(
             &json_config,
             "test_category",
@@ -307,10 +553,14 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Debug,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Json,
                     colored: Some(true),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
                     stream: StreamType::Stdout,
@@ -319,7 +569,7 @@ mod tests {
         };
 
         println!("Testing JSON formatter with colors:");
-        ten_configure_log(&json_config);
+        let _guard = ten_configure_log(&json_config);
         ten_log(
             &json_config,
             "test_category",
@@ -355,10 +605,14 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Info,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
                     stream: StreamType::Stdout,
@@ -367,7 +621,7 @@ mod tests {
         };
 
         println!("Testing stdout emitter:");
-        ten_configure_log(&stdout_config);
+        let _guard = ten_configure_log(&stdout_config);
         ten_log(
             &stdout_config,
             "test_category",
@@ -394,19 +648,24 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Warn,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::File(FileEmitterConfig {
                     path: test_file.to_string(),
+                    rotation: None,
                 }),
             }]),
         };
 
         println!("Testing stderr emitter:");
-        ten_configure_log(&stderr_config);
+        let _guard = ten_configure_log(&stderr_config);
         ten_log(
             &stderr_config,
             "test_category",
@@ -431,6 +690,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_console_emitter_split_stream() {
+        let split_config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
+                    stream: StreamType::Split(SplitStreamConfig {
+                        threshold: AdvancedLogLevel::Warn,
+                        stdout_colored: None,
+                        stderr_colored: None,
+                    }),
+                }),
+            }]),
+        };
+
+        println!("Testing split stream emitter:");
+        let _guard = ten_configure_log(&split_config);
+        ten_log(
+            &split_config,
+            "test_category",
+            1234,
+            5678,
+            LogLevel::Info,
+            "test_split",
+            "emitter.rs",
+            62,
+            "Routine message to stdout",
+        );
+        ten_log(
+            &split_config,
+            "test_category",
+            1234,
+            5678,
+            LogLevel::Error,
+            "test_split",
+            "emitter.rs",
+            63,
+            "Error message to stderr",
+        );
+
+        thread::sleep(Duration::from_millis(100));
+    }
+
     #[test]
     fn test_file_emitter_plain() {
         let temp_file = tempfile::NamedTempFile::new().unwrap();
@@ -442,18 +756,23 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Info,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::File(FileEmitterConfig {
                     path: test_file.to_string(),
+                    rotation: None,
                 }),
             }]),
         };
 
-        ten_configure_log(&file_plain_config);
+        let _guard = ten_configure_log(&file_plain_config);
         ten_log(
             &file_plain_config,
             "test_category",
@@ -504,18 +823,23 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Debug,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Json,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::File(FileEmitterConfig {
                     path: test_file.to_string(),
+                    rotation: None,
                 }),
             }]),
         };
 
-        ten_configure_log(&file_json_config);
+        let _guard = ten_configure_log(&file_json_config);
         ten_log(
             &file_json_config,
             "test_category",
@@ -541,6 +865,101 @@ mod tests {
         let _ = fs::remove_file(test_file);
     }
 
+    #[test]
+    fn test_file_emitter_ignores_colored_setting() {
+        // A handler that sets `colored: Some(true)` (e.g. copy-pasted from a
+        // console handler) must still write plain, escape-code-free output
+        // when targeting a file, for both formatters.
+        let plain_file = tempfile::NamedTempFile::new().unwrap();
+        let plain_path = plain_file.path().to_str().unwrap().to_string();
+        let json_file = tempfile::NamedTempFile::new().unwrap();
+        let json_path = json_file.path().to_str().unwrap().to_string();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![
+                AdvancedLogHandler {
+                    matchers: vec![AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: None,
+                        message_regex: None,
+                        message_contains: None,
+                    }],
+                    formatter: AdvancedLogFormatter {
+                        formatter_type: FormatterType::Plain,
+                        colored: Some(true),
+                        pattern: None,
+                        time_format: None,
+                    },
+                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                        path: plain_path.clone(),
+                        rotation: None,
+                    }),
+                },
+                AdvancedLogHandler {
+                    matchers: vec![AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: None,
+                        message_regex: None,
+                        message_contains: None,
+                    }],
+                    formatter: AdvancedLogFormatter {
+                        formatter_type: FormatterType::Json,
+                        colored: Some(true),
+                        pattern: None,
+                        time_format: None,
+                    },
+                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                        path: json_path.clone(),
+                        rotation: None,
+                    }),
+                },
+            ]),
+        };
+
+        let _guard = ten_configure_log(&config);
+        ten_log(
+            &config,
+            "plain_colored",
+            1,
+            1,
+            LogLevel::Error,
+            "f",
+            "file.rs",
+            1,
+            "plain colored message",
+        );
+        ten_log(
+            &config,
+            "json_colored",
+            1,
+            1,
+            LogLevel::Error,
+            "f",
+            "file.rs",
+            1,
+            "json colored message",
+        );
+
+        thread::sleep(Duration::from_millis(200));
+
+        let plain_content = fs::read_to_string(&plain_path).unwrap();
+        let json_content = fs::read_to_string(&json_path).unwrap();
+
+        assert!(
+            !plain_content.contains('\x1b'),
+            "plain file output must not contain ANSI escapes: \
+             {plain_content:?}"
+        );
+        assert!(
+            !json_content.contains('\x1b'),
+            "json file output must not contain ANSI escapes: \
+             {json_content:?}"
+        );
+        assert!(plain_content.contains("plain colored message"));
+        assert!(json_content.contains("json colored message"));
+    }
+
     #[test]
     fn test_category_matchers_matching_messages() {
         use std::{fs, thread, time::Duration};
@@ -558,23 +977,30 @@ mod tests {
                     AdvancedLogMatcher {
                         level: AdvancedLogLevel::Info,
                         category: Some("auth".to_string()),
+                        message_regex: None,
+                        message_contains: None,
                     },
                     AdvancedLogMatcher {
                         level: AdvancedLogLevel::Debug,
                         category: Some("database".to_string()),
+                        message_regex: None,
+                        message_contains: None,
                     },
                 ],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::File(FileEmitterConfig {
                     path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
                 }),
             }]),
         };
 
-        ten_configure_log(&config);
+        let _guard = ten_configure_log(&config);
 
         // Messages that should be logged (matching configured rules)
         info!(target: "auth", "Auth service started"); // Matches auth + info
@@ -618,23 +1044,30 @@ mod tests {
                     AdvancedLogMatcher {
                         level: AdvancedLogLevel::Info,
                         category: Some("auth".to_string()),
+                        message_regex: None,
+                        message_contains: None,
                     },
                     AdvancedLogMatcher {
                         level: AdvancedLogLevel::Debug,
                         category: Some("database".to_string()),
+                        message_regex: None,
+                        message_contains: None,
                     },
                 ],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Plain,
                     colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::File(FileEmitterConfig {
                     path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
                 }),
             }]),
         };
 
-        ten_configure_log(&config);
+        let _guard = ten_configure_log(&config);
 
         // Messages that should not be logged (level mismatch)
         debug!(target: "auth", "Auth debug message"); // Won't match: auth only allows info
@@ -662,87 +1095,357 @@ mod tests {
     }
 
     #[test]
-    fn test_multiple_handlers_simplified() {
-        use tracing::{debug, info, warn};
+    fn test_category_matcher_regex_family() {
+        use tracing::info;
 
-        // Create two temporary files for different handlers
-        let auth_file = tempfile::NamedTempFile::new().unwrap();
-        let db_file = tempfile::NamedTempFile::new().unwrap();
+        let log_file =
+            tempfile::NamedTempFile::new().expect("Failed to create temp file");
 
         let config = AdvancedLogConfig {
-            level: Some(AdvancedLogLevel::Debug),
-            handlers: Some(vec![
-                // Handler 1: Auth logs (INFO and above) to auth_file
-                AdvancedLogHandler {
-                    matchers: vec![AdvancedLogMatcher {
-                        level: AdvancedLogLevel::Info,
-                        category: Some("auth".to_string()),
-                    }],
-                    formatter: AdvancedLogFormatter {
-                        formatter_type: FormatterType::Plain,
-                        colored: Some(false),
-                    },
-                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
-                        path: auth_file.path().to_str().unwrap().to_string(),
-                    }),
-                },
-                // Handler 2: Database logs (all levels) to db_file
-                AdvancedLogHandler {
-                    matchers: vec![AdvancedLogMatcher {
-                        level: AdvancedLogLevel::Debug,
-                        category: Some("database".to_string()),
-                    }],
-                    formatter: AdvancedLogFormatter {
-                        formatter_type: FormatterType::Plain,
-                        colored: Some(false),
-                    },
-                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
-                        path: db_file.path().to_str().unwrap().to_string(),
-                    }),
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: Some(".*_asr_extension$".to_string()),
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
                 },
-            ]),
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
+                }),
+            }]),
         };
 
-        ten_configure_log(&config);
+        let _guard = ten_configure_log(&config);
 
-        // Auth logs at different levels
-        info!(target: "auth", "User login successful"); // Should appear in auth_file
-        warn!(target: "auth", "Failed login attempt"); // Should appear in auth_file
-        debug!(target: "auth", "Auth token details"); // Should NOT appear in auth_file
+        info!(target: "azure_asr_extension", "Azure ASR ready"); // Matches the family regex
+        info!(target: "google_asr_extension", "Google ASR ready"); // Matches the family regex
+        info!(target: "video_extension", "Video ready"); // Doesn't match the suffix
 
-        // Database logs at different levels
-        info!(target: "database", "Connection established"); // Should appear in db_file
-        debug!(target: "database", "Query executed: SELECT * FROM users"); // Should appear in db_file
-        debug!(target: "database", "Connection pool stats: 5 active"); // Should appear in db_file
+        thread::sleep(Duration::from_millis(100));
 
-        // Other category logs (should not appear in either file)
-        info!(target: "network", "Server started");
-        debug!(target: "network", "Socket initialized");
+        let log_content = fs::read_to_string(log_file.path())
+            .expect("Failed to read log file");
 
-        // Force flush logs
-        ten_configure_log(&AdvancedLogConfig { level: None, handlers: None });
+        assert!(log_content.contains("Azure ASR ready"));
+        assert!(log_content.contains("Google ASR ready"));
+        assert!(!log_content.contains("Video ready"));
+    }
 
-        // Read and verify auth file contents
-        let auth_content = fs::read_to_string(auth_file.path())
-            .expect("Failed to read auth log file");
+    #[test]
+    fn test_ten_log_plain_and_regex_category_matchers() {
+        // `ten_log` (unlike a raw `tracing::info!(target: "...", ...)` call)
+        // attaches its runtime category as an event field rather than as the
+        // static `Metadata::target()`, so this exercises plain and regex
+        // category matchers the way the framework's real C entry point
+        // actually calls them.
+        let log_file =
+            tempfile::NamedTempFile::new().expect("Failed to create temp file");
 
-        // Verify auth file contents
-        assert!(
-            auth_content.contains("User login successful"),
-            "Auth file should contain info level message"
-        );
-        assert!(
-            auth_content.contains("Failed login attempt"),
-            "Auth file should contain warn level message"
-        );
-        assert!(
-            !auth_content.contains("Auth token details"),
-            "Auth file should not contain debug level message"
-        );
-        assert!(
-            !auth_content.contains("database"),
-            "Auth file should not contain database logs"
-        );
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Debug),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![
+                    AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: Some("auth".to_string()),
+                        message_regex: None,
+                        message_contains: None,
+                    },
+                    AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: Some(".*_asr_extension$".to_string()),
+                        message_regex: None,
+                        message_contains: None,
+                    },
+                ],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        ten_log(
+            &config,
+            "auth",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            1,
+            "Auth service started",
+        );
+        ten_log(
+            &config,
+            "auth",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            2,
+            "Auth service debug message",
+        );
+        ten_log(
+            &config,
+            "azure_asr_extension",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            3,
+            "Azure ASR ready",
+        );
+        ten_log(
+            &config,
+            "unknown",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            4,
+            "unknown category message",
+        );
+
+        thread::sleep(Duration::from_millis(200));
+
+        let log_content = fs::read_to_string(log_file.path())
+            .expect("Failed to read log file");
+
+        assert!(log_content.contains("Auth service started"));
+        assert!(log_content.contains("Azure ASR ready"));
+        assert!(!log_content.contains("Auth service debug message"));
+        assert!(!log_content.contains("unknown category message"));
+    }
+
+    #[test]
+    fn test_hierarchical_category_level_overrides() {
+        let log_file =
+            tempfile::NamedTempFile::new().expect("Failed to create temp file");
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Trace),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![
+                    AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: None,
+                        message_regex: None,
+                        message_contains: None,
+                    },
+                    AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Debug,
+                        category: Some("db".to_string()),
+                        message_regex: None,
+                        message_contains: None,
+                    },
+                    AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Trace,
+                        category: Some("db::pool".to_string()),
+                        message_regex: None,
+                        message_contains: None,
+                    },
+                ],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        // "db::pool::stats" inherits "db::pool" (most specific): trace
+        // passes.
+        ten_log(
+            &config,
+            "db::pool::stats",
+            1,
+            1,
+            LogLevel::Verbose,
+            "f",
+            "file.rs",
+            1,
+            "pool trace detail",
+        );
+        // "db::other" inherits "db" (no "db::pool" prefix match): debug
+        // passes, but trace below does not.
+        ten_log(
+            &config,
+            "db::other",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            2,
+            "db debug detail",
+        );
+        ten_log(
+            &config,
+            "db::other",
+            1,
+            1,
+            LogLevel::Verbose,
+            "f",
+            "file.rs",
+            3,
+            "db trace detail",
+        );
+        // "unrelated" falls through to the categoryless global default
+        // (info): info passes, debug does not.
+        ten_log(
+            &config,
+            "unrelated",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            4,
+            "global info detail",
+        );
+        ten_log(
+            &config,
+            "unrelated",
+            1,
+            1,
+            LogLevel::Debug,
+            "f",
+            "file.rs",
+            5,
+            "global debug detail",
+        );
+
+        thread::sleep(Duration::from_millis(200));
+
+        let log_content = fs::read_to_string(log_file.path())
+            .expect("Failed to read log file");
+
+        assert!(log_content.contains("pool trace detail"));
+        assert!(log_content.contains("db debug detail"));
+        assert!(!log_content.contains("db trace detail"));
+        assert!(log_content.contains("global info detail"));
+        assert!(!log_content.contains("global debug detail"));
+    }
+
+    #[test]
+    fn test_multiple_handlers_simplified() {
+        use tracing::{debug, info, warn};
+
+        // Create two temporary files for different handlers
+        let auth_file = tempfile::NamedTempFile::new().unwrap();
+        let db_file = tempfile::NamedTempFile::new().unwrap();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Debug),
+            handlers: Some(vec![
+                // Handler 1: Auth logs (INFO and above) to auth_file
+                AdvancedLogHandler {
+                    matchers: vec![AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: Some("auth".to_string()),
+                        message_regex: None,
+                        message_contains: None,
+                    }],
+                    formatter: AdvancedLogFormatter {
+                        formatter_type: FormatterType::Plain,
+                        colored: Some(false),
+                        pattern: None,
+                        time_format: None,
+                    },
+                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                        path: auth_file.path().to_str().unwrap().to_string(),
+                        rotation: None,
+                    }),
+                },
+                // Handler 2: Database logs (all levels) to db_file
+                AdvancedLogHandler {
+                    matchers: vec![AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Debug,
+                        category: Some("database".to_string()),
+                        message_regex: None,
+                        message_contains: None,
+                    }],
+                    formatter: AdvancedLogFormatter {
+                        formatter_type: FormatterType::Plain,
+                        colored: Some(false),
+                        pattern: None,
+                        time_format: None,
+                    },
+                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                        path: db_file.path().to_str().unwrap().to_string(),
+                        rotation: None,
+                    }),
+                },
+            ]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        // Auth logs at different levels
+        info!(target: "auth", "User login successful"); // Should appear in auth_file
+        warn!(target: "auth", "Failed login attempt"); // Should appear in auth_file
+        debug!(target: "auth", "Auth token details"); // Should NOT appear in auth_file
+
+        // Database logs at different levels
+        info!(target: "database", "Connection established"); // Should appear in db_file
+        debug!(target: "database", "Query executed: SELECT * FROM users"); // Should appear in db_file
+        debug!(target: "database", "Connection pool stats: 5 active"); // Should appear in db_file
+
+        // Other category logs (should not appear in either file)
+        info!(target: "network", "Server started");
+        debug!(target: "network", "Socket initialized");
+
+        // Force flush logs
+        let _guard = ten_configure_log(&AdvancedLogConfig { level: None, handlers: None });
+
+        // Read and verify auth file contents
+        let auth_content = fs::read_to_string(auth_file.path())
+            .expect("Failed to read auth log file");
+
+        // Verify auth file contents
+        assert!(
+            auth_content.contains("User login successful"),
+            "Auth file should contain info level message"
+        );
+        assert!(
+            auth_content.contains("Failed login attempt"),
+            "Auth file should contain warn level message"
+        );
+        assert!(
+            !auth_content.contains("Auth token details"),
+            "Auth file should not contain debug level message"
+        );
+        assert!(
+            !auth_content.contains("database"),
+            "Auth file should not contain database logs"
+        );
         assert!(
             !auth_content.contains("network"),
             "Auth file should not contain network logs"
@@ -782,7 +1485,7 @@ mod tests {
             handlers: None,
         };
 
-        ten_configure_log(&config_no_handlers);
+        let _guard = ten_configure_log(&config_no_handlers);
         ten_log(
             &config_no_handlers,
             "test_category",
@@ -798,6 +1501,72 @@ mod tests {
         thread::sleep(Duration::from_millis(100));
     }
 
+    #[test]
+    fn test_reload_raises_category_level() {
+        let log_file =
+            tempfile::NamedTempFile::new().expect("Failed to create temp file");
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: Some("auth".to_string()),
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
+                }),
+            }]),
+        };
+
+        let mut handle = ten_configure_log(&config);
+
+        debug!(target: "auth", "Auth debug before reload"); // Below the "info" floor
+
+        // Raise "auth" to debug at runtime, without re-initializing.
+        let reloaded = AdvancedLogConfig {
+            level: config.level.clone(),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Debug,
+                    category: Some("auth".to_string()),
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: log_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
+                }),
+            }]),
+        };
+        handle.apply(&reloaded).expect("reload should succeed");
+
+        debug!(target: "auth", "Auth debug after reload"); // Now passes
+
+        thread::sleep(Duration::from_millis(100));
+
+        let log_content = fs::read_to_string(log_file.path())
+            .expect("Failed to read log file");
+
+        assert!(!log_content.contains("Auth debug before reload"));
+        assert!(log_content.contains("Auth debug after reload"));
+    }
+
     #[test]
     fn test_actual_logging_output() {
         let config = AdvancedLogConfig {
@@ -806,10 +1575,14 @@ mod tests {
                 matchers: vec![AdvancedLogMatcher {
                     level: AdvancedLogLevel::Trace,
                     category: None,
+                    message_regex: None,
+                    message_contains: None,
                 }],
                 formatter: AdvancedLogFormatter {
                     formatter_type: FormatterType::Json,
                     colored: Some(true),
+                    pattern: None,
+                    time_format: None,
                 },
                 emitter: AdvancedLogEmitter::Console(ConsoleEmitterConfig {
                     stream: StreamType::Stdout,
@@ -817,7 +1590,7 @@ mod tests {
             }]),
         };
 
-        ten_configure_log(&config);
+        let _guard = ten_configure_log(&config);
 
         ten_log(
             &config,
@@ -878,4 +1651,861 @@ mod tests {
 
         thread::sleep(Duration::from_millis(100));
     }
+
+    #[test]
+    fn test_formatter_plain_custom_pattern() {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_str().unwrap();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: Some(
+                        "[{level}] {target} {func}:{line} - {msg}"
+                            .to_string(),
+                    ),
+                    time_format: Some("uptime".to_string()),
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: path.to_string(),
+                    rotation: None,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        ten_log(
+            &config,
+            "test_category",
+            1234,
+            5678,
+            LogLevel::Info,
+            "custom_func",
+            "custom.rs",
+            42,
+            "Custom pattern message",
+        );
+
+        thread::sleep(Duration::from_millis(100));
+
+        let content = std::fs::read_to_string(path).unwrap();
+
+        // The custom pattern omits pid/tid and the default separators, and
+        // puts the function/line ahead of the message.
+        assert!(content.contains("[INFO] test_category custom_func:42"));
+        assert!(content.contains("Custom pattern message"));
+        assert!(!content.contains("[1234:5678]"));
+    }
+
+    #[test]
+    fn test_file_emitter_size_rotation() {
+        let temp_dir =
+            tempfile::tempdir().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("app.log");
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: path.to_str().unwrap().to_string(),
+                    rotation: Some(FileRotation::Size {
+                        max_bytes: 64,
+                        max_files: Some(2),
+                        compress: false,
+                    }),
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        for i in 0..20 {
+            ten_log(
+                &config,
+                "test_category",
+                1234,
+                5678,
+                LogLevel::Info,
+                "test_func",
+                "test.rs",
+                i,
+                "Rotation filler message",
+            );
+        }
+
+        thread::sleep(Duration::from_millis(200));
+
+        assert!(path.exists(), "Active log file should still exist");
+        let backup = PathBuf::from(format!("{}.1", path.display()));
+        assert!(
+            backup.exists(),
+            "Expected at least one rotated backup to exist"
+        );
+    }
+
+    #[test]
+    fn test_file_emitter_size_rotation_unbounded_retention() {
+        let temp_dir =
+            tempfile::tempdir().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("app.log");
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: path.to_str().unwrap().to_string(),
+                    rotation: Some(FileRotation::Size {
+                        max_bytes: 64,
+                        max_files: None,
+                        compress: false,
+                    }),
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        for i in 0..20 {
+            ten_log(
+                &config,
+                "test_category",
+                1234,
+                5678,
+                LogLevel::Info,
+                "test_func",
+                "test.rs",
+                i,
+                "Rotation filler message",
+            );
+        }
+
+        thread::sleep(Duration::from_millis(200));
+
+        assert!(path.exists(), "Active log file should still exist");
+        let backup = PathBuf::from(format!("{}.1", path.display()));
+        assert!(
+            backup.exists(),
+            "Expected at least one rotated backup to exist"
+        );
+    }
+
+    #[test]
+    fn test_memory_emitter_query_json() {
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Memory(MemoryEmitterConfig {
+                    keep: Duration::from_secs(3600),
+                    max_records: Some(100),
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        ten_log(
+            &config,
+            "auth",
+            1234,
+            5678,
+            LogLevel::Info,
+            "login",
+            "auth.rs",
+            10,
+            "User logged in",
+        );
+        ten_log(
+            &config,
+            "database",
+            1234,
+            5678,
+            LogLevel::Info,
+            "query",
+            "db.rs",
+            20,
+            "Query executed",
+        );
+
+        thread::sleep(Duration::from_millis(100));
+
+        let mut err_msg: *mut c_char = std::ptr::null_mut();
+        let filter_json =
+            r#"{"level": "info", "category": "auth", "limit": 10}"#;
+
+        let result_json = unsafe {
+            let c_string = std::ffi::CString::new(filter_json).unwrap();
+            ten_rust_query_logs_json(c_string.as_ptr(), &mut err_msg)
+        };
+
+        assert!(err_msg.is_null());
+        assert!(!result_json.is_null());
+
+        let result_string = unsafe {
+            let s = std::ffi::CStr::from_ptr(result_json)
+                .to_string_lossy()
+                .into_owned();
+            ten_rust_free_cstring(result_json);
+            s
+        };
+
+        assert!(result_string.contains("User logged in"));
+        assert!(!result_string.contains("Query executed"));
+    }
+
+    #[test]
+    fn test_memory_emitter_drain_json() {
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Memory(MemoryEmitterConfig {
+                    keep: Duration::from_secs(3600),
+                    max_records: Some(100),
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        ten_log(
+            &config,
+            "crash_report",
+            1234,
+            5678,
+            LogLevel::Info,
+            "panic_hook",
+            "panic.rs",
+            5,
+            "about to crash",
+        );
+
+        thread::sleep(Duration::from_millis(100));
+
+        let mut err_msg: *mut c_char = std::ptr::null_mut();
+        let filter_json = r#"{"level": "info", "category": "crash_report"}"#;
+
+        let drained_json = unsafe {
+            let c_string = std::ffi::CString::new(filter_json).unwrap();
+            ten_rust_drain_logs_json(c_string.as_ptr(), &mut err_msg)
+        };
+        assert!(err_msg.is_null());
+        assert!(!drained_json.is_null());
+        let drained_string = unsafe {
+            let s = std::ffi::CStr::from_ptr(drained_json)
+                .to_string_lossy()
+                .into_owned();
+            ten_rust_free_cstring(drained_json);
+            s
+        };
+        assert!(drained_string.contains("about to crash"));
+
+        // A second drain (or a plain query) finds nothing left behind.
+        let requeried_json = unsafe {
+            let c_string = std::ffi::CString::new(filter_json).unwrap();
+            ten_rust_query_logs_json(c_string.as_ptr(), &mut err_msg)
+        };
+        assert!(!requeried_json.is_null());
+        let requeried_string = unsafe {
+            let s = std::ffi::CStr::from_ptr(requeried_json)
+                .to_string_lossy()
+                .into_owned();
+            ten_rust_free_cstring(requeried_json);
+            s
+        };
+        assert!(!requeried_string.contains("about to crash"));
+    }
+
+    #[test]
+    fn test_log_handle_drop_flushes_pending_records() {
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Memory(MemoryEmitterConfig {
+                    keep: Duration::from_secs(3600),
+                    max_records: Some(100),
+                }),
+            }]),
+        };
+
+        let guard = ten_configure_log(&config);
+        guard.set_overflow_policy(OverflowPolicy::Block);
+
+        ten_log(
+            &config,
+            "pipeline",
+            1,
+            1,
+            LogLevel::Info,
+            "flush_check",
+            "mod.rs",
+            1,
+            "queued before drop",
+        );
+
+        // No sleep: dropping the handle must flush and join the writer
+        // thread, so every record queued ahead of the drop is guaranteed to
+        // have reached the memory emitter by the time `drop` returns.
+        drop(guard);
+
+        let records = query_logs(RecordFilter {
+            category: Some("pipeline".to_string()),
+            ..RecordFilter::default()
+        });
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, "queued before drop");
+    }
+
+    #[test]
+    fn test_overflow_drop_with_summary_reports_dropped_count() {
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Memory(MemoryEmitterConfig {
+                    keep: Duration::from_secs(3600),
+                    max_records: Some(50_000),
+                }),
+            }]),
+        };
+
+        let guard = ten_configure_log(&config);
+        guard.set_overflow_policy(OverflowPolicy::DropWithSummary);
+
+        // Flood the bounded channel faster than the writer thread can
+        // drain it, so some of these `try_send` calls hit
+        // `TrySendError::Full` and get counted as dropped instead of
+        // blocking the caller.
+        for i in 0..20_000 {
+            ten_log(
+                &config,
+                "overflow_flood",
+                1,
+                1,
+                LogLevel::Info,
+                "flood",
+                "mod.rs",
+                1,
+                &format!("flood {i}"),
+            );
+        }
+
+        // Give the writer thread time to drain the backlog, then send one
+        // more record: the next send that actually succeeds after a run of
+        // drops is what queues the "N messages dropped" summary line.
+        thread::sleep(Duration::from_millis(200));
+        ten_log(
+            &config,
+            "overflow_flood",
+            1,
+            1,
+            LogLevel::Info,
+            "flood",
+            "mod.rs",
+            1,
+            "settle",
+        );
+
+        drop(guard);
+
+        let records = query_logs(RecordFilter::default());
+        let summary =
+            records.iter().find(|r| r.message.contains("dropped"));
+        assert!(
+            summary.is_some(),
+            "expected a \"N messages dropped\" summary record after \
+             flooding the channel under OverflowPolicy::DropWithSummary"
+        );
+    }
+
+    #[test]
+    fn test_message_contains_filter() {
+        use tracing::info;
+
+        let auth_file = tempfile::NamedTempFile::new().unwrap();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: Some("auth".to_string()),
+                    message_regex: None,
+                    message_contains: Some("failed".to_string()),
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                    path: auth_file.path().to_str().unwrap().to_string(),
+                    rotation: None,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        info!(target: "auth", "login failed for user bob");
+        info!(target: "auth", "login succeeded for user alice");
+
+        // Force flush.
+        let _guard =
+            ten_configure_log(&AdvancedLogConfig { level: None, handlers: None });
+
+        let content = fs::read_to_string(auth_file.path())
+            .expect("Failed to read auth log file");
+
+        assert!(
+            content.contains("login failed for user bob"),
+            "should keep the message matching message_contains"
+        );
+        assert!(
+            !content.contains("login succeeded for user alice"),
+            "should drop the message not matching message_contains"
+        );
+    }
+
+    #[test]
+    fn test_ten_log_category_and_message_contains_filter() {
+        // Exercises `HandlerFilter`/`CompiledMessageMatcher` through
+        // `ten_log`'s runtime category argument rather than a raw
+        // `tracing::info!(target: "...", ...)` call, so a category matcher
+        // combined with `message_contains` actually filters `ten_log`
+        // records by category, not just by message.
+        let auth_file = tempfile::NamedTempFile::new().unwrap();
+        let db_file = tempfile::NamedTempFile::new().unwrap();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![
+                AdvancedLogHandler {
+                    matchers: vec![AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: Some("auth".to_string()),
+                        message_regex: None,
+                        message_contains: Some("failed".to_string()),
+                    }],
+                    formatter: AdvancedLogFormatter {
+                        formatter_type: FormatterType::Plain,
+                        colored: Some(false),
+                        pattern: None,
+                        time_format: None,
+                    },
+                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                        path: auth_file.path().to_str().unwrap().to_string(),
+                        rotation: None,
+                    }),
+                },
+                AdvancedLogHandler {
+                    matchers: vec![AdvancedLogMatcher {
+                        level: AdvancedLogLevel::Info,
+                        category: Some("database".to_string()),
+                        message_regex: None,
+                        message_contains: Some("failed".to_string()),
+                    }],
+                    formatter: AdvancedLogFormatter {
+                        formatter_type: FormatterType::Plain,
+                        colored: Some(false),
+                        pattern: None,
+                        time_format: None,
+                    },
+                    emitter: AdvancedLogEmitter::File(FileEmitterConfig {
+                        path: db_file.path().to_str().unwrap().to_string(),
+                        rotation: None,
+                    }),
+                },
+            ]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        // Matches the "auth" handler's category and message_contains.
+        ten_log(
+            &config,
+            "auth",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            1,
+            "login failed for user bob",
+        );
+        // Same message, wrong category: must not leak into the auth file.
+        ten_log(
+            &config,
+            "database",
+            1,
+            1,
+            LogLevel::Info,
+            "f",
+            "file.rs",
+            2,
+            "query failed for user bob",
+        );
+
+        thread::sleep(Duration::from_millis(200));
+
+        let auth_content = fs::read_to_string(auth_file.path())
+            .expect("Failed to read auth log file");
+        let db_content = fs::read_to_string(db_file.path())
+            .expect("Failed to read db log file");
+
+        assert!(auth_content.contains("login failed for user bob"));
+        assert!(!auth_content.contains("query failed for user bob"));
+        assert!(db_content.contains("query failed for user bob"));
+        assert!(!db_content.contains("login failed for user bob"));
+    }
+
+    #[test]
+    fn test_syslog_emitter_unix_socket() {
+        use std::os::unix::net::UnixDatagram;
+        use tracing::error;
+
+        let socket_dir = tempfile::tempdir().unwrap();
+        let socket_path = socket_dir.path().join("syslog.sock");
+        let receiver = UnixDatagram::bind(&socket_path).unwrap();
+        receiver
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: Some("auth".to_string()),
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Syslog(SyslogEmitterConfig {
+                    transport: SyslogTransport::Unix {
+                        path: socket_path.to_str().unwrap().to_string(),
+                    },
+                    app_name: "ten_test".to_string(),
+                    facility: SyslogFacility::Local0,
+                    format: SyslogMessageFormat::Rfc5424,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        error!(target: "auth", "disk quota exceeded");
+
+        let mut buf = [0u8; 1024];
+        let len =
+            receiver.recv(&mut buf).expect("expected a syslog datagram");
+        let message = String::from_utf8_lossy(&buf[..len]);
+
+        // Local0 (16) * 8 + error severity (3) = 131.
+        assert!(
+            message.starts_with("<131>1 "),
+            "unexpected PRI/version: {message}"
+        );
+        assert!(
+            message.contains(" ten_test "),
+            "missing app-name: {message}"
+        );
+        assert!(
+            message.contains(" auth "),
+            "missing category as MSGID: {message}"
+        );
+        assert!(
+            message.contains("disk quota exceeded"),
+            "missing message: {message}"
+        );
+
+        // PROCID carries both the pid and tid so the daemon-side log still
+        // distinguishes threads within the same process.
+        assert!(
+            message.contains(" ten_test 0:0 "),
+            "expected PROCID to be `pid:tid`: {message}"
+        );
+    }
+
+    #[test]
+    fn test_syslog_emitter_udp_transport() {
+        use std::net::UdpSocket;
+        use tracing::warn;
+
+        let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let receiver_addr = receiver.local_addr().unwrap().to_string();
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: Some("net".to_string()),
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Syslog(SyslogEmitterConfig {
+                    transport: SyslogTransport::Udp { addr: receiver_addr },
+                    app_name: "ten_test".to_string(),
+                    facility: SyslogFacility::Daemon,
+                    format: SyslogMessageFormat::Rfc3164,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        warn!(target: "net", "connection retrying");
+
+        let mut buf = [0u8; 1024];
+        let (len, _) =
+            receiver.recv_from(&mut buf).expect("expected a syslog datagram");
+        let message = String::from_utf8_lossy(&buf[..len]);
+
+        // Daemon (3) * 8 + warn severity (4) = 28.
+        assert!(
+            message.starts_with("<28>"),
+            "unexpected PRI for RFC 3164 framing: {message}"
+        );
+        assert!(
+            message.contains("connection retrying"),
+            "missing message: {message}"
+        );
+    }
+
+    #[test]
+    fn test_otlp_emitter_posts_export_request() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+        use tracing::error;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut chunk = [0u8; 4096];
+            loop {
+                let n = stream.read(&mut chunk).unwrap();
+                request.extend_from_slice(&chunk[..n]);
+                if request.windows(4).any(|w| w == b"\r\n\r\n") && n < chunk.len()
+                {
+                    break;
+                }
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            request
+        });
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: Some("otlp_test".to_string()),
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::Otlp(OtlpEmitterConfig {
+                    endpoint: format!("http://{addr}/v1/logs"),
+                    service_name: "ten_test_service".to_string(),
+                    headers: vec![(
+                        "Authorization".to_string(),
+                        "Bearer test-token".to_string(),
+                    )],
+                    resource_attributes: Vec::new(),
+                    protocol: OtlpProtocol::HttpJson,
+                    batch_max_records: 1,
+                    flush_interval: Duration::from_secs(5),
+                    queue_capacity: 16,
+                }),
+            }]),
+        };
+
+        let _guard = ten_configure_log(&config);
+
+        error!(target: "otlp_test", "exporter smoke test");
+
+        let request_bytes =
+            handle.join().expect("collector thread should not panic");
+        let request = String::from_utf8_lossy(&request_bytes);
+
+        assert!(
+            request.starts_with("POST /v1/logs HTTP/1.1\r\n"),
+            "unexpected request line: {request}"
+        );
+        assert!(
+            request.contains("Authorization: Bearer test-token\r\n"),
+            "missing custom header: {request}"
+        );
+        assert!(
+            request.contains("\"service.name\""),
+            "missing resource attribute: {request}"
+        );
+        assert!(
+            request.contains("\"ten_test_service\""),
+            "missing service name value: {request}"
+        );
+        assert!(
+            request.contains("exporter smoke test"),
+            "missing log body: {request}"
+        );
+        // Error severity number per the OTLP log data model.
+        assert!(
+            request.contains("\"severityNumber\":17"),
+            "missing severity number: {request}"
+        );
+    }
+
+    #[test]
+    fn test_rolling_file_retention_sweeper_stops_on_drop() {
+        // A time-rotated `RollingFile` emitter with `max_files`/`compress`
+        // set spawns a background retention-sweeper thread. Dropping the
+        // `LogHandle` must stop and join that thread rather than leaking it,
+        // so this must return well before the sweeper's own sweep interval
+        // would otherwise wake it up.
+        let log_dir = tempfile::tempdir().unwrap();
+        let log_path = log_dir.path().join("app.log");
+
+        let config = AdvancedLogConfig {
+            level: Some(AdvancedLogLevel::Info),
+            handlers: Some(vec![AdvancedLogHandler {
+                matchers: vec![AdvancedLogMatcher {
+                    level: AdvancedLogLevel::Info,
+                    category: None,
+                    message_regex: None,
+                    message_contains: None,
+                }],
+                formatter: AdvancedLogFormatter {
+                    formatter_type: FormatterType::Plain,
+                    colored: Some(false),
+                    pattern: None,
+                    time_format: None,
+                },
+                emitter: AdvancedLogEmitter::RollingFile(
+                    RollingFileEmitterConfig {
+                        path: log_path.to_str().unwrap().to_string(),
+                        rotation: RollingFileRotation::Minutely,
+                        max_files: Some(3),
+                        compress: true,
+                    },
+                ),
+            }]),
+        };
+
+        let guard = ten_configure_log(&config);
+
+        let start = std::time::Instant::now();
+        drop(guard);
+
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "dropping LogHandle should stop the retention sweeper promptly, \
+             not block for a full sweep interval"
+        );
+    }
 }
@@ -6,7 +6,11 @@
 //
 #[cfg(test)]
 mod tests {
-    use ten_rust::utils::regex::{is_alphanumeric_characters, regex_match};
+    use ten_rust::utils::regex::{
+        is_alphanumeric_characters, is_valid_identifier, regex_cache_len,
+        regex_captures, regex_match, regex_set_any, regex_set_cache_len,
+        regex_set_match, IdentifierMode,
+    };
 
     #[test]
     fn test_is_alphanumeric_characters_1() {
@@ -29,6 +33,24 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn test_is_alphanumeric_characters_ascii_only_rejects_unicode() {
+        let text = "扩展_a";
+        assert!(!is_alphanumeric_characters(text));
+    }
+
+    #[test]
+    fn test_is_valid_identifier_unicode_accepts_unicode() {
+        let text = "扩展_a";
+        assert!(is_valid_identifier(text, IdentifierMode::Unicode));
+    }
+
+    #[test]
+    fn test_is_valid_identifier_unicode_still_rejects_symbols() {
+        let text = "extension_.*";
+        assert!(!is_valid_identifier(text, IdentifierMode::Unicode));
+    }
+
     #[test]
     fn test_regex_match_substring_1() {
         let pattern = "^extension_a_.*$";
@@ -114,6 +136,143 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    fn test_regex_match_reuses_cached_pattern() {
+        let pattern = "^extension_.*$";
+
+        // The same pattern compiled and matched repeatedly should keep
+        // returning consistent results once it's cached.
+        for _ in 0..3 {
+            assert!(regex_match(pattern, "extension_a").unwrap());
+            assert!(!regex_match(pattern, "other_a").unwrap());
+        }
+    }
+
+    #[test]
+    fn test_regex_set_match_returns_all_matching_indices() {
+        let patterns = ["^extension_.*$", ".*_asr_extension$", "^db::.*"];
+
+        let matches =
+            regex_set_match(&patterns, "extension_a_asr_extension").unwrap();
+        assert_eq!(matches, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_regex_set_match_no_match() {
+        let patterns = ["^extension_.*$", "^db::.*"];
+
+        let matches = regex_set_match(&patterns, "unrelated").unwrap();
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_regex_set_match_invalid_pattern() {
+        let patterns = ["*"];
+
+        let result = regex_set_match(&patterns, "extension_a");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_regex_set_any_true_only_when_some_pattern_matches() {
+        let patterns = ["^extension_.*$", "^db::.*"];
+
+        assert!(regex_set_any(&patterns, "extension_a").unwrap());
+        assert!(!regex_set_any(&patterns, "unrelated").unwrap());
+    }
+
+    #[test]
+    fn test_regex_set_match_cache_key_does_not_collide_across_pattern_splits()
+    {
+        // ["a\nb", "c"] and ["a", "b\nc"] must not hash to the same cache
+        // slot despite both joining to "a\nb\nc": if the second call
+        // wrongly reused the first call's RegexSet, matching "a" against
+        // it (patterns "a\nb" and "c", neither of which matches "a")
+        // would wrongly return no matches instead of [0].
+        let split_early = ["a\nb", "c"];
+        let split_late = ["a", "b\nc"];
+
+        let _ = regex_set_match(&split_early, "c").unwrap();
+        let late_matches = regex_set_match(&split_late, "a").unwrap();
+
+        assert_eq!(late_matches, vec![0]);
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn test_fancy_regex_match_lookahead() {
+        use ten_rust::utils::regex::fancy_regex_match;
+
+        // `(?=...)` lookahead isn't supported by the plain `regex` crate.
+        let pattern = r"^extension_(?=a)";
+        assert!(fancy_regex_match(pattern, "extension_a").unwrap());
+        assert!(!fancy_regex_match(pattern, "extension_b").unwrap());
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn test_fancy_regex_match_backreference() {
+        use ten_rust::utils::regex::fancy_regex_match;
+
+        // Backreferences aren't supported by the plain `regex` crate either.
+        let pattern = r"^(\w+)_\1$";
+        assert!(fancy_regex_match(pattern, "extension_extension").unwrap());
+        assert!(!fancy_regex_match(pattern, "extension_other").unwrap());
+    }
+
+    #[test]
+    fn test_regex_match_rejects_oversized_pattern() {
+        let pattern = "a".repeat(2000);
+
+        let result = regex_match(&pattern, "aaa");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_regex_set_match_rejects_oversized_pattern() {
+        let patterns = ["ok".to_string(), "a".repeat(2000)];
+        let patterns: Vec<&str> =
+            patterns.iter().map(String::as_str).collect();
+
+        let result = regex_set_match(&patterns, "ok");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn test_fancy_regex_match_rejects_oversized_pattern() {
+        use ten_rust::utils::regex::fancy_regex_match;
+
+        let pattern = "a".repeat(2000);
+
+        let result = fancy_regex_match(&pattern, "aaa");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_regex_captures_extracts_named_groups() {
+        let pattern = r"^(?P<category>[a-z_]+)_(?P<id>\d+)$";
+
+        let captures = regex_captures(pattern, "extension_42").unwrap();
+        let captures = captures.expect("pattern should match");
+        assert_eq!(captures.get("category").unwrap(), "extension");
+        assert_eq!(captures.get("id").unwrap(), "42");
+    }
+
+    #[test]
+    fn test_regex_captures_no_match_returns_none() {
+        let pattern = r"^(?P<id>\d+)$";
+
+        let captures = regex_captures(pattern, "not_a_number").unwrap();
+        assert!(captures.is_none());
+    }
+
+    #[test]
+    fn test_regex_captures_invalid_pattern() {
+        let result = regex_captures("(", "anything");
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_regex_match_suffix_2() {
         let pattern = ".*_asr_extension$";
@@ -125,4 +284,54 @@ mod tests {
         let result = regex_match(pattern, text).unwrap();
         assert!(!result);
     }
+
+    // `utils::regex::MAX_CACHED_PATTERNS` is private; mirror its value here
+    // so this test still fails loudly if the cap is ever changed without
+    // updating this test.
+    const MAX_CACHED_PATTERNS: usize = 512;
+
+    #[test]
+    fn test_regex_cache_evicts_oldest_beyond_capacity() {
+        // Drive REGEX_CACHE well past its capacity with distinct patterns,
+        // each used only once, the way an adversarial or generated source of
+        // patterns would.
+        for i in 0..(MAX_CACHED_PATTERNS + 50) {
+            let pattern = format!("^unique_pattern_{i}$");
+            regex_match(&pattern, "irrelevant").unwrap();
+        }
+
+        assert!(
+            regex_cache_len() <= MAX_CACHED_PATTERNS,
+            "cache grew to {} entries, exceeding the {} entry cap",
+            regex_cache_len(),
+            MAX_CACHED_PATTERNS
+        );
+
+        // The very first pattern inserted should have been evicted long
+        // ago; re-matching it must still work (by recompiling), proving
+        // eviction drops entries rather than silently corrupting the cache.
+        assert!(regex_match("^unique_pattern_0$", "unique_pattern_0").unwrap());
+    }
+
+    #[test]
+    fn test_regex_set_cache_evicts_oldest_beyond_capacity() {
+        for i in 0..(MAX_CACHED_PATTERNS + 50) {
+            let pattern = format!("^unique_set_pattern_{i}$");
+            let patterns = [pattern.as_str()];
+            regex_set_match(&patterns, "irrelevant").unwrap();
+        }
+
+        assert!(
+            regex_set_cache_len() <= MAX_CACHED_PATTERNS,
+            "set cache grew to {} entries, exceeding the {} entry cap",
+            regex_set_cache_len(),
+            MAX_CACHED_PATTERNS
+        );
+
+        let patterns = ["^unique_set_pattern_0$"];
+        assert_eq!(
+            regex_set_match(&patterns, "unique_set_pattern_0").unwrap(),
+            vec![0]
+        );
+    }
 }